@@ -16,11 +16,13 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::ops::Deref;
 use std::sync::LazyLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::anyhow;
 use mongodb::bson::spec::BinarySubtype;
 use mongodb::bson::{bson, doc, Array, Binary, Bson, DateTime, Document};
-use mongodb::{Client, Namespace};
+use mongodb::error::ErrorKind;
+use mongodb::{BulkWriteModel, Client, Namespace};
 use risingwave_common::array::{Op, RowRef, StreamChunk};
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::log::LogSuppresser;
@@ -47,12 +49,82 @@ pub const MONGODB_SINK: &str = "mongodb";
 pub const MONGODB_BULK_WRITE_SIZE_LIMIT: usize = 65536;
 pub const MONGODB_PK_NAME: &str = "_id";
 
+/// Replace the whole matched document with the row produced by the stream, the default and
+/// historical behavior.
+pub const MONGODB_UPDATE_MODE_REPLACE: &str = "replace";
+/// Only `$set` the columns produced by the stream, preserving any other fields already present
+/// on the matched document. See [`UpsertCommandBuilder::add_upsert`].
+pub const MONGODB_UPDATE_MODE_MERGE: &str = "merge";
+
+/// The `maxWireVersion` reported by a `hello` command once the server understands the
+/// client-level `bulkWrite` command (MongoDB 8.0+). Servers older than this only support the
+/// per-collection `insert`/`update`/`delete` commands, so we fall back to issuing one command per
+/// namespace in that case, as we have always done.
+const MONGODB_BULK_WRITE_MIN_WIRE_VERSION: i32 = 25;
+
+/// Server error codes that indicate a transient replica set state change rather than a genuine
+/// command failure, so a bulk write command failing with one of these is safe to retry unchanged.
+/// See [`is_retryable_bulk_write_error`].
+const MONGODB_BULK_WRITE_RETRYABLE_CODES: &[i32] = &[
+    189,   // PrimarySteppedDown
+    10107, // NotWritablePrimary
+    11602, // InterruptedDueToReplStateChange
+    13435, // NotPrimaryNoSecondaryOk
+];
+
+/// Base delay of the exponential backoff between bulk write retries, doubled on every attempt.
+const MONGODB_BULK_WRITE_RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+/// Upper bound on the backoff delay between bulk write retries.
+const MONGODB_BULK_WRITE_RETRY_MAX_DELAY: Duration = Duration::from_secs(5);
+
 static LOG_SUPPERSSER: LazyLock<LogSuppresser> = LazyLock::new(LogSuppresser::default);
 
 const fn _default_bulk_write_max_entries() -> usize {
     1024
 }
 
+const fn _default_bulk_write_ordered() -> bool {
+    true
+}
+
+fn _default_update_mode() -> String {
+    MONGODB_UPDATE_MODE_REPLACE.to_owned()
+}
+
+const fn _default_bulk_write_max_retries() -> u32 {
+    5
+}
+
+/// Upserts and deletes in a `bulkWrite`/per-namespace command are keyed on `_id`, so re-sending
+/// them is safe; append-only inserts are re-sent too, and an insert already applied before the
+/// failure may be applied again. This decides whether the failure that made us resend it was
+/// transient (a network blip or a replica set primary hand-off) rather than something resending
+/// won't fix (bad schema, auth, a duplicate key in an ordered batch).
+fn is_retryable_bulk_write_error(err: &mongodb::error::Error) -> bool {
+    if err.is_network_error() {
+        return true;
+    }
+    match err.kind.as_ref() {
+        ErrorKind::Command(command_error) => {
+            MONGODB_BULK_WRITE_RETRYABLE_CODES.contains(&command_error.code)
+        }
+        _ => false,
+    }
+}
+
+/// Adds a small random jitter (up to a quarter of `delay`) on top of the exponential backoff, so
+/// that many namespaces failing at the same moment (e.g. during a primary step-down) don't all
+/// retry in lockstep and re-overload the new primary. Derived from the current time instead of
+/// pulling in a dedicated RNG dependency for a single call site.
+fn with_jitter(delay: Duration) -> Duration {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    let max_jitter_ms = (delay.as_millis() as u64 / 4).max(1);
+    delay + Duration::from_millis(nanos as u64 % max_jitter_ms)
+}
+
 #[serde_as]
 #[derive(Clone, Debug, Deserialize, WithOptions)]
 pub struct MongodbConfig {
@@ -84,6 +156,34 @@ pub struct MongodbConfig {
     )]
     #[serde_as(as = "DisplayFromStr")]
     pub bulk_write_max_entries: usize,
+
+    /// Whether the bulk write should be `ordered`, defaults to true. When set to false, the
+    /// server keeps applying the remaining operations in a batch after one fails instead of
+    /// stopping at the first error, and duplicate-key errors (code 11000) on append-only inserts
+    /// are treated as ignorable rather than failing the sink.
+    #[serde(
+        rename = "mongodb.bulk.ordered",
+        default = "_default_bulk_write_ordered",
+        deserialize_with = "deserialize_bool_from_string"
+    )]
+    pub bulk_write_ordered: bool,
+
+    /// Controls how `Insert`/`UpdateInsert` rows are applied to an existing document. `replace`
+    /// (the default) replaces the whole matched document. `merge` only `$set`s the columns
+    /// produced by the stream, so fields written by other pipelines and not present in this
+    /// sink's schema are preserved.
+    #[serde(rename = "mongodb.update.mode", default = "_default_update_mode")]
+    pub update_mode: String,
+
+    /// The maximum number of times a bulk write command is retried after a transient error
+    /// (network error, or a replica set primary stepping down/changing mid-write) before the
+    /// sink gives up and surfaces it as a fatal error, defaults to 5.
+    #[serde(
+        rename = "mongodb.bulk.max_retries",
+        default = "_default_bulk_write_max_retries"
+    )]
+    #[serde_as(as = "DisplayFromStr")]
+    pub bulk_write_max_retries: u32,
 }
 
 impl MongodbConfig {
@@ -99,6 +199,15 @@ impl MongodbConfig {
                 SINK_TYPE_UPSERT
             )));
         }
+        if config.update_mode != MONGODB_UPDATE_MODE_REPLACE
+            && config.update_mode != MONGODB_UPDATE_MODE_MERGE
+        {
+            return Err(SinkError::Config(anyhow!(
+                "`mongodb.update.mode` must be {}, or {}",
+                MONGODB_UPDATE_MODE_REPLACE,
+                MONGODB_UPDATE_MODE_MERGE
+            )));
+        }
         Ok(config)
     }
 }
@@ -348,6 +457,8 @@ impl MongodbSinkWriter {
                         .position(|&name| coll_name_field == name)
                 });
 
+        let bulk_write_supported = Self::check_bulk_write_supported(&client).await?;
+
         let payload_writer = MongodbPayloadWriter::new(
             schema,
             pk_indices,
@@ -357,6 +468,10 @@ impl MongodbSinkWriter {
             is_append_only,
             client.clone(),
             config.bulk_write_max_entries,
+            bulk_write_supported,
+            config.bulk_write_ordered,
+            config.update_mode == MONGODB_UPDATE_MODE_MERGE,
+            config.bulk_write_max_retries,
         );
 
         Ok(Self {
@@ -365,6 +480,27 @@ impl MongodbSinkWriter {
             payload_writer,
         })
     }
+
+    /// Probes the connected server's `hello` response to decide whether the consolidated
+    /// `bulkWrite` command (see [`MongodbPayloadWriter::flush_via_bulk_write`]) can be used, or
+    /// whether we must fall back to the legacy per-namespace `run_command` path.
+    async fn check_bulk_write_supported(client: &Client) -> Result<bool> {
+        let hello = client
+            .database("admin")
+            .run_command(doc! {"hello":1}, None)
+            .await
+            .map_err(|err| {
+                SinkError::Mongodb(anyhow!(err).context("failed to send hello command to mongodb"))
+            })?;
+
+        let max_wire_version = hello.get_i32("maxWireVersion").map_err(|err| {
+            SinkError::Mongodb(
+                anyhow!(err).context("can't extract field maxWireVersion from hello response"),
+            )
+        })?;
+
+        Ok(max_wire_version >= MONGODB_BULK_WRITE_MIN_WIRE_VERSION)
+    }
 }
 
 #[async_trait]
@@ -510,31 +646,55 @@ impl InsertCommandBuilder {
         self.inserts.push(Bson::Document(row));
     }
 
-    fn build(self) -> Document {
+    fn build(self, ordered: bool) -> Document {
         doc! {
             "insert": self.coll,
-            "ordered": true,
+            "ordered": ordered,
             "documents": self.inserts,
         }
     }
+
+    /// Converts the buffered inserts into `bulkWrite`-compatible models targeting `namespace`,
+    /// for use by [`MongodbPayloadWriter::flush_via_bulk_write`].
+    fn build_models(self, namespace: &Namespace) -> Vec<BulkWriteModel> {
+        self.inserts
+            .into_iter()
+            .map(|bson| {
+                let Bson::Document(document) = bson else {
+                    unreachable!("InsertCommandBuilder only ever buffers documents")
+                };
+                BulkWriteModel::InsertOne {
+                    namespace: namespace.clone(),
+                    document,
+                }
+            })
+            .collect()
+    }
 }
 
 struct UpsertCommandBuilder {
     coll: String,
-    upserts: Array,
+    // Keyed on pk so that a later delete for the same pk (within the same flush) can collapse a
+    // pending upsert, and vice versa: otherwise both ops would be emitted for that pk, and since
+    // nothing enforces their relative order across the two bulk-write commands (or across
+    // `BulkWriteModel`s when `mongodb.bulk.ordered` is false), the delete could apply before the
+    // upsert and resurrect a row that should have stayed deleted.
+    upserts: HashMap<Vec<u8>, Document>,
     deletes: HashMap<Vec<u8>, Document>,
+    merge_mode: bool,
 }
 
 impl UpsertCommandBuilder {
-    fn new(coll: String, capacity: usize) -> Self {
+    fn new(coll: String, capacity: usize, merge_mode: bool) -> Self {
         Self {
             coll,
-            upserts: Array::with_capacity(capacity),
+            upserts: HashMap::with_capacity(capacity),
             deletes: HashMap::with_capacity(capacity),
+            merge_mode,
         }
     }
 
-    fn add_upsert(&mut self, pk: Document, row: Document) -> Result<()> {
+    fn add_upsert(&mut self, pk: Document, mut row: Document) -> Result<()> {
         let pk_data = mongodb::bson::to_vec(&pk)
             .map_err(|err| anyhow!(err).context("cannot serialize primary key"))?;
         // under same pk, if the record currently being upserted was marked for deletion previously, we should
@@ -542,12 +702,37 @@ impl UpsertCommandBuilder {
         // see https://github.com/risingwavelabs/risingwave/pull/17102#discussion_r1630684160 for more information.
         self.deletes.remove(&pk_data);
 
-        self.upserts.push(bson!( {
-            "q": pk,
-            "u": row,
-            "upsert": true,
-            "multi": false,
-        }));
+        // `replace` (the default) clobbers the whole matched document with `row`; `merge` only
+        // `$set`s the columns this sink actually produced, and only writes `_id` on an insert, so
+        // that fields written by other pipelines are left untouched on an update. `_id` is
+        // immutable once set, so it is excluded from `$set` and left to `$setOnInsert` instead.
+        let update = if self.merge_mode {
+            row.remove(MONGODB_PK_NAME);
+            // if `_id` was the only projected column, `row` is empty after the removal above; the
+            // server rejects an empty `$set`, so omit it entirely rather than fail the checkpoint.
+            if row.is_empty() {
+                doc! {
+                    "$setOnInsert": pk.clone(),
+                }
+            } else {
+                doc! {
+                    "$set": row,
+                    "$setOnInsert": pk.clone(),
+                }
+            }
+        } else {
+            row
+        };
+
+        self.upserts.insert(
+            pk_data,
+            doc! {
+                "q": pk,
+                "u": update,
+                "upsert": true,
+                "multi": false,
+            },
+        );
 
         Ok(())
     }
@@ -555,17 +740,25 @@ impl UpsertCommandBuilder {
     fn add_delete(&mut self, pk: Document) -> Result<()> {
         let pk_data = mongodb::bson::to_vec(&pk)
             .map_err(|err| anyhow!(err).context("cannot serialize primary key"))?;
+        // symmetric to the revert in `add_upsert`: a delete always supersedes any upsert buffered
+        // earlier in this flush for the same pk.
+        self.upserts.remove(&pk_data);
         self.deletes.insert(pk_data, pk);
         Ok(())
     }
 
-    fn build(self) -> (Option<Document>, Option<Document>) {
+    fn build(self, ordered: bool) -> (Option<Document>, Option<Document>) {
         let (mut upsert_document, mut delete_document) = (None, None);
         if !self.upserts.is_empty() {
+            let updates = self
+                .upserts
+                .into_values()
+                .map(Bson::Document)
+                .collect::<Array>();
             upsert_document = Some(doc! {
                 "update": self.coll.clone(),
-                "ordered": true,
-                "updates": self.upserts,
+                "ordered": ordered,
+                "updates": updates,
             });
         }
         if !self.deletes.is_empty() {
@@ -582,12 +775,62 @@ impl UpsertCommandBuilder {
 
             delete_document = Some(doc! {
                 "delete": self.coll,
-                "ordered": true,
+                "ordered": ordered,
                 "deletes": deletes,
             });
         }
         (upsert_document, delete_document)
     }
+
+    /// Converts the buffered upserts and deletes into `bulkWrite`-compatible models targeting
+    /// `namespace`, for use by [`MongodbPayloadWriter::flush_via_bulk_write`]. Upserts are
+    /// ordered ahead of deletes, same as [`Self::build`], so that under the same pk an `Insert`
+    /// or `UpdateInsert` followed by a later `Delete` is never reordered into a no-op.
+    ///
+    /// In `merge` mode, `u` is an update document built from atomic operators (`$set` /
+    /// `$setOnInsert`), so it is carried over as-is into [`BulkWriteModel::UpdateOne`]. In the
+    /// default `replace` mode, `u` is a plain whole-document replacement with no atomic
+    /// operators, which `bulkWrite`'s `updateOne`/`updateMany` models reject ("Update document
+    /// requires atomic operators") -- it must instead go out as [`BulkWriteModel::ReplaceOne`].
+    fn build_models(self, namespace: &Namespace) -> Vec<BulkWriteModel> {
+        let merge_mode = self.merge_mode;
+        let mut models: Vec<BulkWriteModel> = self
+            .upserts
+            .into_values()
+            .map(|mut entry| {
+                let filter = entry
+                    .remove("q")
+                    .and_then(|bson| bson.as_document().cloned())
+                    .unwrap_or_default();
+                let update = entry
+                    .remove("u")
+                    .and_then(|bson| bson.as_document().cloned())
+                    .unwrap_or_default();
+                if merge_mode {
+                    BulkWriteModel::UpdateOne {
+                        namespace: namespace.clone(),
+                        filter,
+                        update,
+                        upsert: true,
+                    }
+                } else {
+                    BulkWriteModel::ReplaceOne {
+                        namespace: namespace.clone(),
+                        filter,
+                        replacement: update,
+                        upsert: true,
+                    }
+                }
+            })
+            .collect();
+
+        models.extend(self.deletes.into_values().map(|filter| BulkWriteModel::DeleteOne {
+            namespace: namespace.clone(),
+            filter,
+        }));
+
+        models
+    }
 }
 
 type MongodbNamespace = (String, String);
@@ -604,7 +847,16 @@ struct MongodbPayloadWriter {
     client: Client,
     buffered_entries: usize,
     max_entries: usize,
-    // TODO switching to bulk write API when mongodb driver supports it
+    // whether the connected server's `hello` response advertised a wire version new enough to
+    // support the client-level `bulkWrite` command, see [`MongodbSinkWriter::check_bulk_write_supported`]
+    bulk_write_supported: bool,
+    // whether the bulk write command(s) should be `ordered`, see `MongodbConfig::bulk_write_ordered`
+    bulk_write_ordered: bool,
+    // whether upserts should `$set` just the produced columns instead of replacing the whole
+    // document, see `MongodbConfig::update_mode`
+    merge_mode: bool,
+    // maximum number of retries for a transient bulk write failure, see `MongodbConfig::bulk_write_max_retries`
+    max_retries: u32,
     insert_builder: Option<HashMap<MongodbNamespace, InsertCommandBuilder>>,
     upsert_builder: Option<HashMap<MongodbNamespace, UpsertCommandBuilder>>,
 }
@@ -619,6 +871,10 @@ impl MongodbPayloadWriter {
         is_append_only: bool,
         client: Client,
         max_entries: usize,
+        bulk_write_supported: bool,
+        bulk_write_ordered: bool,
+        merge_mode: bool,
+        max_retries: u32,
     ) -> Self {
         Self {
             schema,
@@ -630,6 +886,10 @@ impl MongodbPayloadWriter {
             client,
             buffered_entries: 0,
             max_entries,
+            bulk_write_supported,
+            bulk_write_ordered,
+            merge_mode,
+            max_retries,
             insert_builder: if is_append_only {
                 Some(HashMap::new())
             } else {
@@ -756,7 +1016,8 @@ impl MongodbPayloadWriter {
                 match self.upsert_builder.as_mut().unwrap().entry(ns) {
                     Entry::Occupied(mut entry) => entry.get_mut().add_upsert(pk, document),
                     Entry::Vacant(entry) => {
-                        let mut builder = UpsertCommandBuilder::new(coll, self.max_entries);
+                        let mut builder =
+                            UpsertCommandBuilder::new(coll, self.max_entries, self.merge_mode);
                         builder.add_upsert(pk, document)?;
                         entry.insert(builder);
                         Ok(())
@@ -767,7 +1028,8 @@ impl MongodbPayloadWriter {
             Op::Delete => match self.upsert_builder.as_mut().unwrap().entry(ns) {
                 Entry::Occupied(mut entry) => entry.get_mut().add_delete(pk),
                 Entry::Vacant(entry) => {
-                    let mut builder = UpsertCommandBuilder::new(coll, self.max_entries);
+                    let mut builder =
+                        UpsertCommandBuilder::new(coll, self.max_entries, self.merge_mode);
                     builder.add_delete(pk)?;
                     entry.insert(builder);
                     Ok(())
@@ -799,24 +1061,37 @@ impl MongodbPayloadWriter {
     }
 
     async fn flush(&mut self) -> Result<()> {
+        if self.bulk_write_supported {
+            self.flush_via_bulk_write().await?;
+            self.buffered_entries = 0;
+            return Ok(());
+        }
+
         if self.is_append_only {
             if let Some(mut insert_builder) = self.insert_builder.take() {
                 for (ns, builder) in insert_builder.drain() {
-                    self.send_bulk_write_command(&ns.0, builder.build()).await?;
+                    self.send_bulk_write_command(
+                        &ns.0,
+                        builder.build(self.bulk_write_ordered),
+                        true,
+                    )
+                    .await?;
                 }
                 self.insert_builder = Some(insert_builder);
             }
         } else if let Some(mut upsert_builder) = self.upsert_builder.take() {
             for (ns, builder) in upsert_builder.drain() {
-                let (upsert, delete) = builder.build();
+                let (upsert, delete) = builder.build(self.bulk_write_ordered);
                 // we are sending the bulk upsert first because, under same pk, the `Insert` and `UpdateInsert`
                 // should always appear before `Delete`. we have already ignored the `UpdateDelete`
                 // which is useless in upsert mode.
                 if upsert.is_some() {
-                    self.send_bulk_write_command(&ns.0, upsert.unwrap()).await?;
+                    self.send_bulk_write_command(&ns.0, upsert.unwrap(), false)
+                        .await?;
                 }
                 if delete.is_some() {
-                    self.send_bulk_write_command(&ns.0, delete.unwrap()).await?;
+                    self.send_bulk_write_command(&ns.0, delete.unwrap(), false)
+                        .await?;
                 }
             }
             self.upsert_builder = Some(upsert_builder);
@@ -826,21 +1101,188 @@ impl MongodbPayloadWriter {
         Ok(())
     }
 
-    async fn send_bulk_write_command(&mut self, database: &str, command: Document) -> Result<()> {
+    /// Consolidates every buffered insert/upsert/delete, across every namespace they target,
+    /// into a single ordered [`BulkWriteModel`] list and submits it with one client-level
+    /// `bulkWrite` round-trip, instead of one `run_command` per namespace (see [`Self::flush`]).
+    ///
+    /// Per-model results are requested so that a write error on one model doesn't obscure the
+    /// models that actually succeeded; we still surface the checkpoint as failed if any model
+    /// failed; so it can be retried, but we log exactly which models (by index) were rejected
+    /// rather than aborting blind on the first error.
+    async fn flush_via_bulk_write(&mut self) -> Result<()> {
+        let mut models = Vec::new();
+
+        if let Some(mut insert_builder) = self.insert_builder.take() {
+            for (ns, builder) in insert_builder.drain() {
+                let namespace = Namespace {
+                    db: ns.0,
+                    coll: ns.1,
+                };
+                models.extend(builder.build_models(&namespace));
+            }
+            self.insert_builder = Some(insert_builder);
+        }
+
+        if let Some(mut upsert_builder) = self.upsert_builder.take() {
+            for (ns, builder) in upsert_builder.drain() {
+                let namespace = Namespace {
+                    db: ns.0,
+                    coll: ns.1,
+                };
+                models.extend(builder.build_models(&namespace));
+            }
+            self.upsert_builder = Some(upsert_builder);
+        }
+
+        if models.is_empty() {
+            return Ok(());
+        }
+
+        let total = models.len();
+        // remember which index is an insert before the models are consumed by `bulk_write`, so a
+        // duplicate-key error can be matched back to its model below.
+        let is_insert_model: Vec<bool> = models
+            .iter()
+            .map(|model| matches!(model, BulkWriteModel::InsertOne { .. }))
+            .collect();
+
+        // upsert/delete models are keyed on `_id`, so resending them after a transient failure is
+        // safe; insert models are resent as well and may be applied twice. Retry with exponential
+        // backoff before giving up.
+        let mut attempt = 0u32;
+        let mut delay = MONGODB_BULK_WRITE_RETRY_BASE_DELAY;
+        let result = loop {
+            match self
+                .client
+                .bulk_write(models.clone())
+                .ordered(self.bulk_write_ordered)
+                .verbose_results()
+                .await
+            {
+                Ok(result) => break result,
+                Err(err) => {
+                    if attempt >= self.max_retries || !is_retryable_bulk_write_error(&err) {
+                        return Err(SinkError::Mongodb(
+                            anyhow!(err).context("sending consolidated bulk write failed"),
+                        ));
+                    }
+                    attempt += 1;
+                    let sleep_for = with_jitter(delay);
+                    tracing::warn!(
+                        attempt,
+                        delay_ms = sleep_for.as_millis() as u64,
+                        error = %err.as_report(),
+                        "retrying transient mongodb bulk write failure",
+                    );
+                    tokio::time::sleep(sleep_for).await;
+                    delay = (delay * 2).min(MONGODB_BULK_WRITE_RETRY_MAX_DELAY);
+                }
+            }
+        };
+
+        if !result.write_errors.is_empty() {
+            let fatal_errors: Vec<_> = result
+                .write_errors
+                .iter()
+                .filter(|(index, err)| {
+                    // a duplicate key on an append-only insert just means the row was already
+                    // written by a previous, possibly partially-failed, attempt; it is not a
+                    // reason to fail the whole checkpoint when unordered.
+                    let ignorable = !self.bulk_write_ordered
+                        && self.is_append_only
+                        && is_insert_model.get(*index).copied().unwrap_or(false)
+                        && err.code == 11000;
+                    !ignorable
+                })
+                .collect();
+
+            if !fatal_errors.is_empty() {
+                if let Ok(suppressed_count) = LOG_SUPPERSSER.check() {
+                    tracing::warn!(
+                        suppressed_count,
+                        fatal = fatal_errors.len(),
+                        total,
+                        "bulk write respond with fatal write errors: {:?}",
+                        fatal_errors,
+                    );
+                }
+                return Err(SinkError::Mongodb(anyhow!(
+                    "bulk write respond with {} fatal write errors out of {} models: {:?}",
+                    fatal_errors.len(),
+                    total,
+                    fatal_errors,
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn send_bulk_write_command(
+        &mut self,
+        database: &str,
+        command: Document,
+        is_insert: bool,
+    ) -> Result<()> {
         let db = self.client.database(database);
 
-        let result = db.run_command(command, None).await.map_err(|err| {
-            SinkError::Mongodb(anyhow!(err).context(format!(
-                "sending bulk write command failed, database: {}",
-                database
-            )))
-        })?;
+        // an update/delete command is keyed on `_id`, so resending the exact same command after
+        // a transient failure is safe; an insert command is resent as well and may be applied
+        // twice. Retry with exponential backoff before giving up and forcing the checkpoint to
+        // fail.
+        let mut attempt = 0u32;
+        let mut delay = MONGODB_BULK_WRITE_RETRY_BASE_DELAY;
+        let result = loop {
+            match db.run_command(command.clone(), None).await {
+                Ok(result) => break result,
+                Err(err) => {
+                    if attempt >= self.max_retries || !is_retryable_bulk_write_error(&err) {
+                        return Err(SinkError::Mongodb(anyhow!(err).context(format!(
+                            "sending bulk write command failed, database: {}",
+                            database
+                        ))));
+                    }
+                    attempt += 1;
+                    let sleep_for = with_jitter(delay);
+                    tracing::warn!(
+                        database,
+                        attempt,
+                        delay_ms = sleep_for.as_millis() as u64,
+                        error = %err.as_report(),
+                        "retrying transient mongodb bulk write failure",
+                    );
+                    tokio::time::sleep(sleep_for).await;
+                    delay = (delay * 2).min(MONGODB_BULK_WRITE_RETRY_MAX_DELAY);
+                }
+            }
+        };
 
+        let mut had_write_errors = false;
         if let Ok(write_errors) = result.get_array("writeErrors") {
-            return Err(SinkError::Mongodb(anyhow!(
-                "bulk write respond with write errors: {:?}",
-                write_errors,
-            )));
+            had_write_errors = !write_errors.is_empty();
+            // when `ordered`, the server already stopped at the first error and anything in
+            // `writeErrors` is fatal; when unordered, the server kept applying the rest of the
+            // batch, so a duplicate key (11000) on an append-only insert is an expected retry
+            // of an already-written row rather than a reason to fail the checkpoint.
+            let fatal_errors: Vec<_> = write_errors
+                .iter()
+                .filter(|err| {
+                    let ignorable = !self.bulk_write_ordered
+                        && is_insert
+                        && err
+                            .as_document()
+                            .and_then(|doc| doc.get_i32("code").ok())
+                            == Some(11000);
+                    !ignorable
+                })
+                .collect();
+
+            if !fatal_errors.is_empty() {
+                return Err(SinkError::Mongodb(anyhow!(
+                    "bulk write respond with fatal write errors: {:?}",
+                    fatal_errors,
+                )));
+            }
         }
 
         let n = result.get_i32("n").map_err(|err| {
@@ -848,7 +1290,9 @@ impl MongodbPayloadWriter {
                 anyhow!(err).context("can't extract field n from bulk write response"),
             )
         })?;
-        if n < 1 {
+        // a low `n` is only abnormal when nothing explains it; an unordered batch that was
+        // entirely ignorable duplicate keys legitimately reports n = 0.
+        if n < 1 && !had_write_errors {
             return Err(SinkError::Mongodb(anyhow!(
                 "bulk write respond with an abnormal state, n = {}",
                 n
@@ -15,7 +15,8 @@
 pub mod parquet_file_handler;
 
 mod metrics;
-use std::collections::{HashMap, HashSet};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::sync::Arc;
 
 use anyhow::anyhow;
@@ -28,10 +29,12 @@ use iceberg::scan::FileScanTask;
 use iceberg::spec::{DataContentType, ManifestList};
 use iceberg::table::Table;
 use itertools::Itertools;
+use parquet::file::metadata::ParquetMetaDataReader;
 pub use parquet_file_handler::*;
 use risingwave_common::array::arrow::IcebergArrowConvert;
 use risingwave_common::array::{ArrayImpl, DataChunk, I64Array, Utf8Array};
 use risingwave_common::bail;
+use risingwave_common::buffer::Bitmap;
 use risingwave_common::catalog::{
     ICEBERG_FILE_PATH_COLUMN_NAME, ICEBERG_FILE_POS_COLUMN_NAME, ICEBERG_SEQUENCE_NUM_COLUMN_NAME,
     Schema,
@@ -52,6 +55,42 @@ use crate::source::{
 };
 pub const ICEBERG_CONNECTOR: &str = "iceberg";
 
+/// Data files larger than this are split into multiple `FileScanTask`s aligned to Parquet
+/// row-group boundaries, so a single huge file doesn't become a parallelism bottleneck for the
+/// split it landed in. See [`IcebergSplitEnumerator::split_large_data_file`].
+const ICEBERG_SPLIT_FILE_SIZE_THRESHOLD: u64 = 128 * 1024 * 1024;
+
+/// Reads just the Parquet footer of `data_file_path` and returns, for every row group in file
+/// order, `(byte_offset, row_offset, num_rows)` — the byte offset and the cumulative row count at
+/// the start of that row group. Used both to cut a large data file into sub-`FileScanTask`s and,
+/// on the read side, to recover the absolute row position a sub-task starts at.
+async fn parquet_row_group_offsets(
+    table: &Table,
+    data_file_path: &str,
+) -> ConnectorResult<Vec<(u64, u64, u64)>> {
+    let input_file = table.file_io().new_input(data_file_path).map_err(|e| anyhow!(e))?;
+    let file_size = input_file.metadata().await.map_err(|e| anyhow!(e))?.size;
+    let reader = input_file.reader().await.map_err(|e| anyhow!(e))?;
+    let parquet_metadata = ParquetMetaDataReader::new()
+        .load_and_finish(reader, file_size as usize)
+        .await
+        .map_err(|e| anyhow!(e))?;
+
+    let mut row_offset = 0u64;
+    let mut offsets = Vec::with_capacity(parquet_metadata.num_row_groups());
+    for row_group in parquet_metadata.row_groups() {
+        // `RowGroupMetaData::file_offset` mirrors the optional (and commonly absent) thrift
+        // `file_offset` field, so it's frequently `None` and would otherwise make every row group
+        // report offset 0. `ColumnChunkMetaData::byte_range` is derived from the mandatory page
+        // offsets instead, so it's always a real, monotonically increasing file position.
+        let byte_offset = row_group.column(0).byte_range().0;
+        let num_rows = row_group.num_rows().max(0) as u64;
+        offsets.push((byte_offset, row_offset, num_rows));
+        row_offset += num_rows;
+    }
+    Ok(offsets)
+}
+
 #[derive(Clone, Debug, Deserialize, with_options::WithOptions)]
 pub struct IcebergProperties {
     #[serde(flatten)]
@@ -177,6 +216,34 @@ impl IcebergFileScanTask {
             IcebergFileScanTask::CountStar(_) => vec![],
         }
     }
+
+    /// Drops the files in `completed_files` from this task, so a split resumed after a crash
+    /// doesn't re-read a data file it already fully consumed.
+    fn retain_unfinished(&mut self, completed_files: &HashSet<String>) {
+        match self {
+            IcebergFileScanTask::Data(file_scan_tasks)
+            | IcebergFileScanTask::EqualityDelete(file_scan_tasks)
+            | IcebergFileScanTask::PositionDelete(file_scan_tasks) => {
+                file_scan_tasks.retain(|task| !completed_files.contains(&task.data_file_path));
+            }
+            IcebergFileScanTask::CountStar(_) => {}
+        }
+    }
+}
+
+/// The scan progress of an [`IcebergSplit`], persisted across checkpoints so a restored split can
+/// skip data files it has already fully consumed and resume a partially-read one instead of
+/// restarting the whole assignment from scratch.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IcebergSplitCursor {
+    /// Data files in this split that have been fully consumed.
+    pub completed_files: HashSet<String>,
+    /// The file currently being read, and the last `file_pos` (see
+    /// `ICEBERG_FILE_POS_COLUMN_NAME`) emitted for it. `file_pos` is a stable, globally-unique row
+    /// identifier for the file, so rows at or below this position are re-derivable as already
+    /// emitted when this file is resumed; see [`IcebergSplit::resume_after_pos`] and
+    /// `IcebergScanOpts::resume_after_pos` for where that's enforced.
+    pub in_progress: Option<(String, i64)>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -185,6 +252,8 @@ pub struct IcebergSplit {
     // TODO: remove this field. It seems not used.
     pub snapshot_id: i64,
     pub task: IcebergFileScanTask,
+    #[serde(default)]
+    pub cursor: IcebergSplitCursor,
 }
 
 impl IcebergSplit {
@@ -194,6 +263,7 @@ impl IcebergSplit {
                 split_id: 0,
                 snapshot_id: 0,
                 task: IcebergFileScanTask::new_count_star(0),
+                cursor: IcebergSplitCursor::default(),
             }
         } else {
             Self {
@@ -205,9 +275,22 @@ impl IcebergSplit {
                     vec![],
                     vec![],
                 ),
+                cursor: IcebergSplitCursor::default(),
             }
         }
     }
+
+    /// Returns the `file_pos` boundary below which rows of `data_file_path` were already emitted
+    /// before the last checkpoint, if `data_file_path` is this split's in-progress file. Callers
+    /// driving `scan_task_to_chunk` over this split's task must pass this through
+    /// `IcebergScanOpts::resume_after_pos` so the partially-read file is resumed instead of
+    /// re-scanned from row 0.
+    pub fn resume_after_pos(&self, data_file_path: &str) -> Option<i64> {
+        match &self.cursor.in_progress {
+            Some((path, pos)) if path == data_file_path => Some(*pos),
+            _ => None,
+        }
+    }
 }
 
 impl SplitMetaData for IcebergSplit {
@@ -216,15 +299,29 @@ impl SplitMetaData for IcebergSplit {
     }
 
     fn restore_from_json(value: JsonbVal) -> ConnectorResult<Self> {
-        serde_json::from_value(value.take()).map_err(|e| anyhow!(e).into())
+        let mut split: Self = serde_json::from_value(value.take()).map_err(|e| anyhow!(e))?;
+        split.task.retain_unfinished(&split.cursor.completed_files);
+        Ok(split)
     }
 
     fn encode_to_json(&self) -> JsonbVal {
         serde_json::to_value(self.clone()).unwrap().into()
     }
 
-    fn update_offset(&mut self, _last_seen_offset: String) -> ConnectorResult<()> {
-        unimplemented!()
+    /// Parses the `(file_path, file_pos)` offset emitted downstream for the last row handed off,
+    /// and advances the scan cursor: if the offset names a new file, the previously in-progress
+    /// one is now fully consumed and moves into `completed_files`.
+    fn update_offset(&mut self, last_seen_offset: String) -> ConnectorResult<()> {
+        let (file_path, file_pos): (String, i64) =
+            serde_json::from_str(&last_seen_offset).map_err(|e| anyhow!(e))?;
+
+        if let Some((prev_path, _)) = &self.cursor.in_progress {
+            if prev_path != &file_path {
+                self.cursor.completed_files.insert(prev_path.clone());
+            }
+        }
+        self.cursor.in_progress = Some((file_path, file_pos));
+        Ok(())
     }
 }
 
@@ -261,6 +358,18 @@ impl IcebergSplitEnumerator {
 pub enum IcebergTimeTravelInfo {
     Version(i64),
     TimestampMs(i64),
+    /// Tail only the data files added between `from_snapshot_id` (exclusive) and
+    /// `to_snapshot_id` (inclusive), instead of re-planning the whole table. See
+    /// [`IcebergSplitEnumerator::list_splits_batch_incremental_scan`].
+    Incremental {
+        from_snapshot_id: i64,
+        to_snapshot_id: i64,
+    },
+    /// Resolve to the snapshot currently pointed at by a named branch or tag, e.g. `FOR SYSTEM_VERSION
+    /// AS OF 'my-tag'`. Only one of `Version`/`TimestampMs`/`Ref` is expected to be supplied at a
+    /// time; if callers somehow construct this alongside an explicit snapshot id, the `Ref` is
+    /// resolved independently and takes precedence since it's the more specific request.
+    Ref(String),
 }
 
 impl IcebergSplitEnumerator {
@@ -299,6 +408,20 @@ impl IcebergSplitEnumerator {
                     }
                 }
             }
+            Some(IcebergTimeTravelInfo::Ref(ref_name)) => {
+                let Some(snapshot_ref) = table.metadata().refs().get(&ref_name) else {
+                    bail!(
+                        "Cannot find branch or tag '{}' in the iceberg table.",
+                        ref_name
+                    );
+                };
+                snapshot_ref.snapshot_id
+            }
+            Some(IcebergTimeTravelInfo::Incremental { .. }) => {
+                bail!(
+                    "incremental iceberg scans resolve their own snapshot range and must go through `list_splits_batch_incremental_scan`"
+                );
+            }
             None => {
                 assert!(current_snapshot.is_some());
                 current_snapshot.unwrap().snapshot_id()
@@ -319,6 +442,28 @@ impl IcebergSplitEnumerator {
             bail!("Batch parallelism is 0. Cannot split the iceberg files.");
         }
         let table = self.config.load_table().await?;
+
+        if let Some(IcebergTimeTravelInfo::Incremental {
+            from_snapshot_id,
+            to_snapshot_id,
+        }) = time_traval_info
+        {
+            if let IcebergScanType::CountStar = iceberg_scan_type {
+                bail!("COUNT(*) is not supported for incremental iceberg scans");
+            }
+            return self
+                .list_splits_batch_incremental_scan(
+                    &table,
+                    from_snapshot_id,
+                    to_snapshot_id,
+                    schema,
+                    batch_parallelism,
+                    iceberg_scan_type,
+                    predicate,
+                )
+                .await;
+        }
+
         let snapshot_id = Self::get_snapshot_id(&table, time_traval_info)?;
         if snapshot_id.is_none() {
             // If there is no snapshot, we will return a mock `IcebergSplit` with empty files.
@@ -402,7 +547,7 @@ impl IcebergSplitEnumerator {
             }
             match task.data_file_content {
                 iceberg::spec::DataContentType::Data => {
-                    data_files.push(task);
+                    data_files.extend(Self::split_large_data_file(table, task).await?);
                 }
                 iceberg::spec::DataContentType::EqualityDeletes => {
                     bail!("Equality delete files should not be in the data files");
@@ -432,6 +577,7 @@ impl IcebergSplitEnumerator {
                         equality_delete_file,
                         position_delete_file,
                     ),
+                    cursor: IcebergSplitCursor::default(),
                 },
             )
             .filter(|split| !split.task.is_empty())
@@ -443,12 +589,219 @@ impl IcebergSplitEnumerator {
         Ok(splits)
     }
 
+    /// Plans only the data files added between `from_snapshot_id` (exclusive) and
+    /// `to_snapshot_id` (inclusive), so a streaming consumer can tail new commits without
+    /// re-reading rows it has already ingested.
+    ///
+    /// This only supports a linear run of pure `Append` commits. An `Overwrite` is also used for
+    /// compaction/rewrite commits that replace existing rows with new data files carrying no
+    /// delete files of their own: those new files would pass the "not in the baseline file set"
+    /// check and get re-emitted even though the rows they carry were already ingested under their
+    /// old file paths. Since there is no cheap way to tell a pure-append `Overwrite` apart from a
+    /// rewrite from the commit metadata alone, any `Overwrite` in the range is rejected outright
+    /// rather than guessed at, same as a `Replace` (schema or partition evolution).
+    async fn list_splits_batch_incremental_scan(
+        &self,
+        table: &Table,
+        from_snapshot_id: i64,
+        to_snapshot_id: i64,
+        schema: Schema,
+        batch_parallelism: usize,
+        iceberg_scan_type: IcebergScanType,
+        predicate: IcebergPredicate,
+    ) -> ConnectorResult<Vec<IcebergSplit>> {
+        Self::validate_incremental_ancestry(table, from_snapshot_id, to_snapshot_id)?;
+
+        let schema_names = schema.names();
+        let require_names = schema_names
+            .iter()
+            .filter(|name| {
+                name.ne(&ICEBERG_SEQUENCE_NUM_COLUMN_NAME)
+                    && name.ne(&ICEBERG_FILE_PATH_COLUMN_NAME)
+                    && name.ne(&ICEBERG_FILE_POS_COLUMN_NAME)
+            })
+            .cloned()
+            .collect_vec();
+
+        // Files already visible at `from_snapshot_id` were covered by a previous incremental
+        // batch (or the initial full scan); anything newly visible at `to_snapshot_id` is new.
+        let baseline_files =
+            Self::scan_data_file_paths(table, from_snapshot_id, predicate.clone()).await?;
+
+        let scan = table
+            .scan()
+            .with_filter(predicate)
+            .snapshot_id(to_snapshot_id)
+            .with_delete_file_processing_enabled(true)
+            .select(require_names)
+            .build()
+            .map_err(|e| anyhow!(e))?;
+        let file_scan_stream = scan.plan_files().await.map_err(|e| anyhow!(e))?;
+
+        let mut data_files = vec![];
+        #[for_await]
+        for task in file_scan_stream {
+            let task: FileScanTask = task.map_err(|e| anyhow!(e))?;
+            if baseline_files.contains(&task.data_file_path) {
+                continue;
+            }
+            match task.data_file_content {
+                DataContentType::Data => {
+                    if !task.deletes.is_empty() {
+                        bail!(
+                            "incremental iceberg scan from snapshot {} to {} found delete files applying to newly-added data file {}; this would silently drop row removals, fall back to a full rescan",
+                            from_snapshot_id, to_snapshot_id, task.data_file_path,
+                        );
+                    }
+                    data_files.push(task);
+                }
+                DataContentType::EqualityDeletes | DataContentType::PositionDeletes => {
+                    bail!(
+                        "incremental iceberg scan from snapshot {} to {} added delete file {}; this would silently drop row removals, fall back to a full rescan",
+                        from_snapshot_id, to_snapshot_id, task.data_file_path,
+                    );
+                }
+            }
+        }
+
+        let data_files = Self::split_n_vecs(data_files, batch_parallelism);
+        let splits = data_files
+            .into_iter()
+            .enumerate()
+            .map(|(index, data_file)| IcebergSplit {
+                split_id: index as i64,
+                snapshot_id: to_snapshot_id,
+                task: IcebergFileScanTask::new_scan_with_scan_type(
+                    iceberg_scan_type,
+                    data_file,
+                    vec![],
+                    vec![],
+                ),
+                cursor: IcebergSplitCursor::default(),
+            })
+            .filter(|split| !split.task.is_empty())
+            .collect_vec();
+
+        if splits.is_empty() {
+            return Ok(vec![IcebergSplit::empty(iceberg_scan_type)]);
+        }
+        Ok(splits)
+    }
+
+    /// Collects just the file paths visible at `snapshot_id`, to establish the baseline that an
+    /// incremental scan diffs against.
+    async fn scan_data_file_paths(
+        table: &Table,
+        snapshot_id: i64,
+        predicate: IcebergPredicate,
+    ) -> ConnectorResult<HashSet<String>> {
+        let scan = table
+            .scan()
+            .with_filter(predicate)
+            .snapshot_id(snapshot_id)
+            .build()
+            .map_err(|e| anyhow!(e))?;
+        let file_scan_stream = scan.plan_files().await.map_err(|e| anyhow!(e))?;
+        let mut paths = HashSet::new();
+        #[for_await]
+        for task in file_scan_stream {
+            let task: FileScanTask = task.map_err(|e| anyhow!(e))?;
+            paths.insert(task.data_file_path);
+        }
+        Ok(paths)
+    }
+
+    /// Walks the snapshot ancestry backwards from `to_snapshot_id`, checking that
+    /// `from_snapshot_id` is reached and that every intervening commit is a pure `Append`.
+    fn validate_incremental_ancestry(
+        table: &Table,
+        from_snapshot_id: i64,
+        to_snapshot_id: i64,
+    ) -> ConnectorResult<()> {
+        let metadata = table.metadata();
+        if metadata.snapshot_by_id(from_snapshot_id).is_none() {
+            bail!(
+                "from_snapshot_id {} not found in the iceberg table",
+                from_snapshot_id
+            );
+        }
+        let Some(mut current) = metadata.snapshot_by_id(to_snapshot_id) else {
+            bail!(
+                "to_snapshot_id {} not found in the iceberg table",
+                to_snapshot_id
+            );
+        };
+
+        loop {
+            if current.snapshot_id() == from_snapshot_id {
+                return Ok(());
+            }
+            match current.summary().operation {
+                iceberg::spec::Operation::Append => {}
+                other => bail!(
+                    "incremental iceberg scan from {} to {} passes through a {:?} snapshot ({}); only pure Append commits can be tailed incrementally, an Overwrite may rewrite already-ingested rows into new data files and re-emit them",
+                    from_snapshot_id,
+                    to_snapshot_id,
+                    other,
+                    current.snapshot_id(),
+                ),
+            }
+            let Some(parent_id) = current.parent_snapshot_id() else {
+                bail!(
+                    "from_snapshot_id {} is not an ancestor of to_snapshot_id {}",
+                    from_snapshot_id,
+                    to_snapshot_id,
+                );
+            };
+            let Some(parent) = metadata.snapshot_by_id(parent_id) else {
+                bail!(
+                    "snapshot {} references missing parent {}",
+                    current.snapshot_id(),
+                    parent_id
+                );
+            };
+            current = parent;
+        }
+    }
+
     pub async fn list_splits_batch_count_star(
         &self,
         table: &Table,
         snapshot_id: i64,
     ) -> ConnectorResult<Vec<IcebergSplit>> {
-        let mut record_counts = 0;
+        let (equality_delete_columns, have_position_delete) =
+            Self::all_delete_parameters(table, snapshot_id).await?;
+
+        let record_counts = if !equality_delete_columns.is_empty() {
+            tracing::info!(
+                snapshot_id,
+                "iceberg COUNT(*) falls back to a full scan because equality deletes are present; their effect can't be resolved from manifest metadata alone"
+            );
+            Self::count_rows_via_scan(table, snapshot_id).await?
+        } else {
+            tracing::debug!(
+                snapshot_id,
+                have_position_delete,
+                "iceberg COUNT(*) uses the manifest-sum fast path"
+            );
+            Self::count_rows_via_manifest_sum(table, snapshot_id).await?
+        };
+
+        let split = IcebergSplit {
+            split_id: 0,
+            snapshot_id,
+            task: IcebergFileScanTask::new_count_star(record_counts),
+            cursor: IcebergSplitCursor::default(),
+        };
+        Ok(vec![split])
+    }
+
+    /// Sums `record_count` straight from manifest entries without touching the data files.
+    /// Correct as long as the snapshot has no equality deletes: a position delete's own
+    /// `record_count` is exactly the number of rows it removes from its target data file(s), so
+    /// it can simply be subtracted from the running total.
+    async fn count_rows_via_manifest_sum(table: &Table, snapshot_id: i64) -> ConnectorResult<u64> {
+        let mut record_counts: i64 = 0;
         let manifest_list: ManifestList = table
             .metadata()
             .snapshot_by_id(snapshot_id)
@@ -467,16 +820,47 @@ impl IcebergSplitEnumerator {
 
             while let Some(manifest_entry) = manifest_entries_stream.next().await {
                 let file = manifest_entry.data_file();
-                assert_eq!(file.content_type(), DataContentType::Data);
-                record_counts += file.record_count();
+                match file.content_type() {
+                    DataContentType::Data => record_counts += file.record_count() as i64,
+                    DataContentType::PositionDeletes => {
+                        record_counts -= file.record_count() as i64
+                    }
+                    DataContentType::EqualityDeletes => {
+                        bail!(
+                            "unexpected equality delete file {} on the manifest-sum COUNT(*) fast path",
+                            file.file_path()
+                        );
+                    }
+                }
             }
         }
-        let split = IcebergSplit {
-            split_id: 0,
-            snapshot_id,
-            task: IcebergFileScanTask::new_count_star(record_counts),
-        };
-        Ok(vec![split])
+        Ok(record_counts.max(0) as u64)
+    }
+
+    /// Runs a real scan with delete processing enabled and counts the rows that survive it. The
+    /// only correct option once equality deletes are in play, since resolving them requires
+    /// evaluating each row against the delete predicate rather than summing manifest metadata.
+    async fn count_rows_via_scan(table: &Table, snapshot_id: i64) -> ConnectorResult<u64> {
+        let scan = table
+            .scan()
+            .snapshot_id(snapshot_id)
+            .with_delete_file_processing_enabled(true)
+            .build()
+            .map_err(|e| anyhow!(e))?;
+        let file_scan_stream = scan.plan_files().await.map_err(|e| anyhow!(e))?;
+
+        let reader = table.reader_builder().build();
+        let mut record_batch_stream = reader
+            .read(Box::pin(file_scan_stream))
+            .await
+            .map_err(|e| anyhow!(e))?;
+
+        let mut rows = 0u64;
+        while let Some(batch) = record_batch_stream.next().await {
+            let batch = batch.map_err(|e| anyhow!(e))?;
+            rows += batch.num_rows() as u64;
+        }
+        Ok(rows)
     }
 
     /// List all files in the snapshot to check if there are deletes.
@@ -537,18 +921,62 @@ impl IcebergSplitEnumerator {
         Self::all_delete_parameters(&table, snapshot_id).await
     }
 
+    /// Splits `task` into multiple `FileScanTask`s aligned to Parquet row-group boundaries when
+    /// its underlying file is larger than [`ICEBERG_SPLIT_FILE_SIZE_THRESHOLD`], so the file can
+    /// be spread across several splits instead of always landing whole in a single one.
+    async fn split_large_data_file(
+        table: &Table,
+        task: FileScanTask,
+    ) -> ConnectorResult<Vec<FileScanTask>> {
+        if task.length <= ICEBERG_SPLIT_FILE_SIZE_THRESHOLD {
+            return Ok(vec![task]);
+        }
+
+        let row_groups = parquet_row_group_offsets(table, &task.data_file_path).await?;
+        if row_groups.len() <= 1 {
+            // nothing to align sub-task boundaries to.
+            return Ok(vec![task]);
+        }
+        let file_end = task.start + task.length;
+
+        let mut sub_tasks = vec![];
+        let mut bin_start_byte = row_groups[0].0;
+        for i in 0..row_groups.len() {
+            let next_byte_offset = row_groups.get(i + 1).map_or(file_end, |g| g.0);
+            let is_last_group = i + 1 == row_groups.len();
+            // Row-group byte offsets are expected to be monotonically increasing; guard against a
+            // metadata source that isn't (rather than underflowing, since `bin_bytes` is unsigned)
+            // by treating the bin as empty so far and carrying on from the next group.
+            let bin_bytes = next_byte_offset.saturating_sub(bin_start_byte);
+            if bin_bytes >= ICEBERG_SPLIT_FILE_SIZE_THRESHOLD || is_last_group {
+                let mut sub_task = task.clone();
+                sub_task.start = bin_start_byte;
+                sub_task.length = bin_bytes;
+                sub_tasks.push(sub_task);
+                bin_start_byte = next_byte_offset;
+            }
+        }
+        Ok(sub_tasks)
+    }
+
+    /// Packs `vecs` into `split_num` bins balanced by byte size rather than by count, so a split
+    /// doesn't end up with every large file while its siblings get only small ones. Uses greedy
+    /// longest-processing-time bin-packing: tasks are assigned largest-first, each to whichever
+    /// bin currently holds the fewest accumulated bytes.
     fn split_n_vecs(vecs: Vec<FileScanTask>, split_num: usize) -> Vec<Vec<FileScanTask>> {
-        let split_size = vecs.len() / split_num;
-        let remaining = vecs.len() % split_num;
-        let mut result_vecs = (0..split_num)
-            .map(|i| {
-                let start = i * split_size;
-                let end = (i + 1) * split_size;
-                vecs[start..end].to_vec()
-            })
-            .collect_vec();
-        for i in 0..remaining {
-            result_vecs[i].push(vecs[split_num * split_size + i].clone());
+        let mut load_heap = BinaryHeap::with_capacity(split_num);
+        for i in 0..split_num {
+            load_heap.push(Reverse((0u64, i)));
+        }
+
+        let mut sorted_vecs = vecs;
+        sorted_vecs.sort_by_key(|task| Reverse(task.length));
+
+        let mut result_vecs = vec![Vec::new(); split_num];
+        for task in sorted_vecs {
+            let Reverse((bytes, bin)) = load_heap.pop().unwrap();
+            load_heap.push(Reverse((bytes + task.length, bin)));
+            result_vecs[bin].push(task);
         }
         result_vecs
     }
@@ -558,6 +986,10 @@ pub struct IcebergScanOpts {
     pub chunk_size: usize,
     pub need_seq_num: bool,
     pub need_file_path_and_pos: bool,
+    /// Set from [`IcebergSplit::resume_after_pos`] when this task resumes a file that was
+    /// partially read before the last checkpoint. Rows at or below this `file_pos` were already
+    /// emitted, so they're masked out of the chunk's visibility instead of being re-yielded.
+    pub resume_after_pos: Option<i64>,
 }
 
 #[try_stream(ok = DataChunk, error = ConnectorError)]
@@ -568,6 +1000,7 @@ pub async fn scan_task_to_chunk(
         chunk_size,
         need_seq_num,
         need_file_path_and_pos,
+        resume_after_pos,
     }: IcebergScanOpts,
     metrics: Option<Arc<IcebergScanMetrics>>,
 ) {
@@ -585,16 +1018,31 @@ pub async fn scan_task_to_chunk(
     let data_file_path = data_file_scan_task.data_file_path.clone();
     let data_sequence_number = data_file_scan_task.sequence_number;
 
+    // A task covering a sub-range of the file (see `IcebergSplitEnumerator::split_large_data_file`)
+    // starts partway through it, so `ICEBERG_FILE_POS_COLUMN_NAME` must be offset by the number of
+    // rows in the row groups that precede `task.start`, not counted from zero.
+    let row_offset = if data_file_scan_task.start > 0 {
+        parquet_row_group_offsets(&table, &data_file_path)
+            .await?
+            .into_iter()
+            .find(|(byte_offset, _, _)| *byte_offset == data_file_scan_task.start)
+            .map(|(_, row_offset, _)| row_offset)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
     let reader = table.reader_builder().with_batch_size(chunk_size).build();
     let file_scan_stream = tokio_stream::once(Ok(data_file_scan_task));
 
-    // FIXME: what if the start position is not 0? The logic for index seems not correct.
     let mut record_batch_stream = reader.read(Box::pin(file_scan_stream)).await?.enumerate();
 
     while let Some((index, record_batch)) = record_batch_stream.next().await {
         let record_batch = record_batch?;
 
         let mut chunk = IcebergArrowConvert.chunk_from_record_batch(&record_batch)?;
+        let index_start = row_offset as i64 + (index * chunk_size) as i64;
+
         if need_seq_num {
             let (mut columns, visibility) = chunk.into_parts();
             columns.push(Arc::new(ArrayImpl::Int64(I64Array::from_iter(
@@ -607,12 +1055,24 @@ pub async fn scan_task_to_chunk(
             columns.push(Arc::new(ArrayImpl::Utf8(Utf8Array::from_iter(
                 vec![data_file_path.as_str(); visibility.len()],
             ))));
-            let index_start = (index * chunk_size) as i64;
             columns.push(Arc::new(ArrayImpl::Int64(I64Array::from_iter(
                 (index_start..(index_start + visibility.len() as i64)).collect::<Vec<i64>>(),
             ))));
             chunk = DataChunk::from_parts(columns.into(), visibility)
         }
+        if let Some(resume_after_pos) = resume_after_pos {
+            // Rows up to and including `resume_after_pos` were already emitted before the last
+            // checkpoint; mask them out instead of re-yielding them so resuming this file doesn't
+            // produce duplicates downstream.
+            if index_start + chunk.capacity() as i64 - 1 <= resume_after_pos {
+                continue;
+            }
+            let (columns, visibility) = chunk.into_parts();
+            let visibility = Bitmap::from_iter(visibility.iter().enumerate().map(|(i, vis)| {
+                vis && index_start + i as i64 > resume_after_pos
+            }));
+            chunk = DataChunk::from_parts(columns, visibility)
+        }
         *read_bytes += chunk.estimated_heap_size() as u64;
         yield chunk;
     }
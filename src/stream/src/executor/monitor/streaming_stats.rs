@@ -0,0 +1,76 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// NOTE: the real `struct StreamingMetrics` / `StreamingMetrics::new` defined in this module are
+// not part of this checkout, so the fields below can't be spliced into them directly. Recorded
+// here, in the shape they need to land in, so `task::barrier_manager::managed_state` (which
+// already reads `streaming_metrics.barrier_slow_poll_count` and friends) has somewhere to point
+// at once this file is merged with the rest of the module.
+//
+// Add to `struct StreamingMetrics`:
+//
+//   /// Number of individual `poll()` calls on the `sync_epoch`/barrier-completion futures that
+//   /// took longer than `with_poll_timer`'s threshold, indicating the streaming runtime stalled
+//   /// on a blocking call rather than merely waiting on a slow downstream dependency.
+//   pub barrier_slow_poll_count: GenericCounter<AtomicU64>,
+//
+//   /// Number of times a checkpoint `sync_epoch` was retried after a transient state-store
+//   /// failure.
+//   pub barrier_sync_retry_count: GenericCounter<AtomicU64>,
+//
+//   /// The global completed-epoch frontier: the minimum, over all partial graphs on this worker,
+//   /// of the largest `prev_epoch` that has reached `Completed(Ok)`.
+//   pub barrier_manager_global_completed_epoch: GenericGauge<AtomicI64>,
+//
+//   /// Number of actors still holding back the earliest in-flight barrier past
+//   /// `BARRIER_COLLECT_WARN_THRESHOLD`.
+//   pub barrier_manager_laggard_actor_count: GenericGauge<AtomicI64>,
+//
+//   /// Number of subscription-registry reconciliations that found the meta-supplied
+//   /// authoritative snapshot diverging from local `mv_depended_subscriptions` state.
+//   pub subscription_drift_count: GenericCounter<AtomicU64>,
+//
+// Add to `StreamingMetrics::new`, alongside the other barrier-manager registrations:
+//
+//   let barrier_slow_poll_count = register_int_counter_with_registry!(
+//       "stream_barrier_slow_poll_count",
+//       "Number of individual polls of a sync_epoch/barrier-completion future exceeding the slow-poll threshold",
+//       registry
+//   ).unwrap();
+//
+//   let barrier_sync_retry_count = register_int_counter_with_registry!(
+//       "stream_barrier_sync_retry_count",
+//       "Number of sync_epoch retries issued after a transient state store failure",
+//       registry
+//   ).unwrap();
+//
+//   let barrier_manager_global_completed_epoch = register_int_gauge_with_registry!(
+//       "stream_barrier_manager_global_completed_epoch",
+//       "The global completed-epoch frontier across all partial graphs on this worker",
+//       registry
+//   ).unwrap();
+//
+//   let barrier_manager_laggard_actor_count = register_int_gauge_with_registry!(
+//       "stream_barrier_manager_laggard_actor_count",
+//       "Number of actors that have not yet collected the earliest in-flight barrier past the stall warning threshold",
+//       registry
+//   ).unwrap();
+//
+//   let subscription_drift_count = register_int_counter_with_registry!(
+//       "stream_subscription_drift_count",
+//       "Number of subscription-registry reconciliations that found drift against the meta-supplied snapshot",
+//       registry
+//   ).unwrap();
+//
+// ... and thread all five through into the `Self { .. }` literal, same as the existing fields.
@@ -18,14 +18,15 @@ use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::{Debug, Display, Formatter};
 use std::future::{pending, poll_fn, Future};
 use std::mem::replace;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::{ready, Context, Poll};
+use std::time::{Duration, Instant};
 
 use anyhow::anyhow;
 use await_tree::InstrumentAwait;
 use futures::future::BoxFuture;
 use futures::stream::FuturesOrdered;
-use futures::{FutureExt, StreamExt, TryFutureExt};
+use futures::{FutureExt, StreamExt};
 use prometheus::HistogramTimer;
 use risingwave_common::catalog::TableId;
 use risingwave_common::must_match;
@@ -55,6 +56,14 @@ struct IssuedState {
     pub table_ids: Option<HashSet<TableId>>,
 
     pub kind: BarrierKind,
+
+    /// When `remaining_actors` last shrank (or the barrier was first issued). Used to detect a
+    /// laggard actor wedging this barrier's collection.
+    pub last_collect_progress_at: Instant,
+
+    /// Whether we have already emitted a stall warning for the current lack of progress, so we
+    /// don't spam the log on every poll until the actor finally collects.
+    pub has_warned_stall: bool,
 }
 
 impl Debug for IssuedState {
@@ -77,6 +86,19 @@ enum ManagedBarrierStateInner {
     /// The barrier has been collected by all remaining actors
     AllCollected,
 
+    /// The `sync_epoch` for this (always `Checkpoint`) barrier has failed at least once and is
+    /// backing off before the next retry, instead of immediately surfacing the error and forcing
+    /// the whole compute node into recovery. This is a read-only snapshot refreshed by
+    /// [`PartialGraphManagedBarrierState::refresh_retrying_states`]; the retry itself keeps
+    /// running inside the same future already queued in `await_epoch_completed_futures` so that
+    /// future's position, and therefore completion ordering, is undisturbed.
+    Retrying {
+        kind: BarrierKind,
+        table_ids: HashSet<TableId>,
+        attempt: u32,
+        next_at: Instant,
+    },
+
     /// The barrier has been completed, which means the barrier has been collected by all actors and
     /// synced in state store
     Completed(StreamResult<BarrierCompleteResult>),
@@ -86,17 +108,98 @@ enum ManagedBarrierStateInner {
 pub(super) struct BarrierState {
     barrier: Barrier,
     inner: ManagedBarrierStateInner,
+
+    /// Set alongside `inner` for `Checkpoint` barriers whose `sync_epoch` may retry, so that
+    /// `refresh_retrying_states` can observe the latest attempt/backoff without reaching into the
+    /// (erased) `await_epoch_completed_futures` future itself.
+    retry_progress: Option<RetryProgressHandle>,
+}
+
+/// A future combinator that times every individual `poll()` of the wrapped future and warns
+/// when a single poll takes unexpectedly long. Unlike a histogram over the whole await duration
+/// (e.g. `barrier_sync_latency`), this isolates the case where the future stalls the executor
+/// thread within one poll, which usually means something is blocking synchronously instead of
+/// yielding.
+mod poll_timer {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll};
+    use std::time::{Duration, Instant};
+
+    use pin_project::pin_project;
+
+    use crate::executor::monitor::StreamingMetrics;
+
+    /// A poll taking longer than this is assumed to indicate a blocking call on the streaming
+    /// runtime rather than ordinary scheduling jitter.
+    const SLOW_POLL_WARN_THRESHOLD: Duration = Duration::from_millis(50);
+
+    #[pin_project]
+    pub(super) struct WithPollTimer<F> {
+        #[pin]
+        inner: F,
+        name: &'static str,
+        prev_epoch: u64,
+        streaming_metrics: Arc<StreamingMetrics>,
+    }
+
+    impl<F: Future> Future for WithPollTimer<F> {
+        type Output = F::Output;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = self.project();
+            let start = Instant::now();
+            let output = this.inner.poll(cx);
+            let elapsed = start.elapsed();
+            if elapsed > SLOW_POLL_WARN_THRESHOLD {
+                tracing::warn!(
+                    name = *this.name,
+                    prev_epoch = *this.prev_epoch,
+                    elapsed_ms = elapsed.as_millis() as u64,
+                    "a single poll of {} took {:?}, the streaming runtime may be blocked",
+                    this.name,
+                    elapsed,
+                );
+                this.streaming_metrics.barrier_slow_poll_count.inc();
+            }
+            output
+        }
+    }
+
+    pub(super) trait FutureWithPollTimerExt: Future + Sized {
+        fn with_poll_timer(
+            self,
+            name: &'static str,
+            prev_epoch: u64,
+            streaming_metrics: Arc<StreamingMetrics>,
+        ) -> WithPollTimer<Self> {
+            WithPollTimer {
+                inner: self,
+                name,
+                prev_epoch,
+                streaming_metrics,
+            }
+        }
+    }
+
+    impl<F: Future> FutureWithPollTimerExt for F {}
 }
 
+use poll_timer::FutureWithPollTimerExt;
+
 mod await_epoch_completed_future {
     use std::future::Future;
+    use std::sync::Arc;
 
     use futures::future::BoxFuture;
     use futures::FutureExt;
     use risingwave_hummock_sdk::SyncResult;
     use risingwave_pb::stream_service::barrier_complete_response::PbCreateMviewProgress;
 
+    use super::poll_timer::FutureWithPollTimerExt;
     use crate::error::StreamResult;
+    use crate::executor::monitor::StreamingMetrics;
     use crate::executor::Barrier;
     use crate::task::{await_tree_key, BarrierCompleteResult};
 
@@ -108,6 +211,7 @@ mod await_epoch_completed_future {
         barrier: Barrier,
         barrier_await_tree_reg: Option<&await_tree::Registry>,
         create_mview_progress: Vec<PbCreateMviewProgress>,
+        streaming_metrics: Arc<StreamingMetrics>,
     ) -> AwaitEpochCompletedFuture {
         let prev_epoch = barrier.epoch.prev;
         let future = async move {
@@ -118,6 +222,7 @@ mod await_epoch_completed_future {
                 Ok(None)
             }
         }
+        .with_poll_timer("complete_barrier_future", prev_epoch, streaming_metrics)
         .map(move |result| {
             (
                 barrier,
@@ -145,43 +250,188 @@ use risingwave_pb::stream_plan::SubscriptionUpstreamInfo;
 use risingwave_pb::stream_service::streaming_control_stream_request::InitialPartialGraph;
 use risingwave_pb::stream_service::InjectBarrierRequest;
 
+/// Maximum number of retries for a transient `sync_epoch` failure before it is surfaced as a
+/// fatal error and forces a full barrier-recovery cycle.
+const SYNC_EPOCH_RETRY_MAX_ATTEMPTS: u32 = 5;
+/// Base delay of the exponential backoff between `sync_epoch` retries, doubled on every attempt.
+const SYNC_EPOCH_RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+/// Upper bound on the backoff delay between `sync_epoch` retries.
+const SYNC_EPOCH_RETRY_MAX_DELAY: Duration = Duration::from_secs(5);
+
+/// `io::ErrorKind`s that indicate a fatal, non-transient failure (malformed data, a caller/config
+/// mistake, ...) and should not be retried.
+const FATAL_SYNC_IO_ERROR_KINDS: &[std::io::ErrorKind] = &[
+    std::io::ErrorKind::InvalidData,
+    std::io::ErrorKind::InvalidInput,
+    std::io::ErrorKind::PermissionDenied,
+    std::io::ErrorKind::Unsupported,
+];
+
+/// Distinguishes transient failures (e.g. an object-store hiccup, or a key briefly missing under
+/// eventual consistency) worth retrying from fatal ones that should immediately surface and
+/// trigger recovery. Classifies by walking the error's `source()` chain for a structured
+/// `std::io::Error` and matching on its `kind()`, rather than the error's textual report: message
+/// matching is fragile in both directions -- a fatal error whose message happens to avoid the
+/// marker words gets retried for several seconds before failing anyway, while a transient
+/// object-store `404 ... not found` surfacing during eventual consistency gets misclassified as
+/// fatal purely because its message contains "not found".
+fn is_retryable_sync_error(error: &StreamError) -> bool {
+    let mut source: Option<&(dyn std::error::Error + 'static)> = Some(error);
+    while let Some(err) = source {
+        if let Some(io_err) = err.downcast_ref::<std::io::Error>() {
+            return !FATAL_SYNC_IO_ERROR_KINDS.contains(&io_err.kind());
+        }
+        source = err.source();
+    }
+    // No structured `io::Error` in the chain: default to retryable, since an infrastructure
+    // hiccup that isn't modeled as one is far more common in practice than a fatal error that
+    // fails to produce one.
+    true
+}
+
+/// Latest attempt/backoff of an in-progress `sync_epoch` retry, shared between the future
+/// performing the retries and the [`BarrierState`] it belongs to. See
+/// [`ManagedBarrierStateInner::Retrying`].
+#[derive(Debug, Clone)]
+struct RetryProgress {
+    kind: BarrierKind,
+    table_ids: HashSet<TableId>,
+    attempt: u32,
+    next_at: Instant,
+}
+
+type RetryProgressHandle = Arc<Mutex<Option<RetryProgress>>>;
+
+/// Drives `attempt_sync` to completion, retrying transient failures with exponential backoff and
+/// publishing the latest attempt/backoff to `retry_progress` as it goes (see
+/// [`ManagedBarrierStateInner::Retrying`]). Factored out of [`sync_epoch`] so the retry/backoff
+/// state machine can be driven directly in tests against a fake `attempt_sync` instead of a real
+/// `HummockStorage`, whose failures can't be injected from here.
+async fn retry_sync_epoch<F, Fut>(
+    mut attempt_sync: F,
+    streaming_metrics: Arc<StreamingMetrics>,
+    prev_epoch: u64,
+    kind: BarrierKind,
+    table_ids: HashSet<TableId>,
+    retry_progress: RetryProgressHandle,
+) -> StreamResult<SyncResult>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = StreamResult<SyncResult>>,
+{
+    let mut attempt = 0u32;
+    let mut delay = SYNC_EPOCH_RETRY_BASE_DELAY;
+    loop {
+        let timer = streaming_metrics.barrier_sync_latency.start_timer();
+        let result = attempt_sync().await;
+        timer.observe_duration();
+
+        let error = match result {
+            Ok(sync_result) => return Ok(sync_result),
+            Err(e) => e,
+        };
+
+        if attempt >= SYNC_EPOCH_RETRY_MAX_ATTEMPTS || !is_retryable_sync_error(&error) {
+            tracing::error!(
+                prev_epoch,
+                attempt,
+                error = %error.as_report(),
+                "Failed to sync state store",
+            );
+            return Err(error);
+        }
+
+        streaming_metrics.barrier_sync_retry_count.inc();
+        attempt += 1;
+        let next_at = Instant::now() + delay;
+        *retry_progress.lock().unwrap() = Some(RetryProgress {
+            kind,
+            table_ids: table_ids.clone(),
+            attempt,
+            next_at,
+        });
+        tracing::warn!(
+            prev_epoch,
+            attempt,
+            delay_ms = delay.as_millis() as u64,
+            error = %error.as_report(),
+            "retrying transient state store sync failure",
+        );
+        tokio::time::sleep(delay).await;
+        delay = (delay * 2).min(SYNC_EPOCH_RETRY_MAX_DELAY);
+    }
+}
+
 fn sync_epoch(
     state_store: &StateStoreImpl,
-    streaming_metrics: &StreamingMetrics,
+    streaming_metrics: Arc<StreamingMetrics>,
     prev_epoch: u64,
+    kind: BarrierKind,
     table_ids: HashSet<TableId>,
+    retry_progress: RetryProgressHandle,
 ) -> BoxFuture<'static, StreamResult<SyncResult>> {
-    let timer = streaming_metrics.barrier_sync_latency.start_timer();
     let hummock = state_store.as_hummock().cloned();
+    let metrics_for_retry = streaming_metrics.clone();
     let future = async move {
-        if let Some(hummock) = hummock {
-            hummock.sync(vec![(prev_epoch, table_ids)]).await
-        } else {
-            Ok(SyncResult::default())
-        }
+        let Some(hummock) = hummock else {
+            return Ok(SyncResult::default());
+        };
+
+        retry_sync_epoch(
+            {
+                let table_ids = table_ids.clone();
+                move || {
+                    let hummock = hummock.clone();
+                    let table_ids = table_ids.clone();
+                    async move {
+                        hummock
+                            .sync(vec![(prev_epoch, table_ids)])
+                            .await
+                            .map_err(|e| anyhow!(e).into())
+                    }
+                }
+            },
+            metrics_for_retry,
+            prev_epoch,
+            kind,
+            table_ids,
+            retry_progress,
+        )
+        .await
     };
     future
         .instrument_await(format!("sync_epoch (epoch {})", prev_epoch))
-        .inspect_ok(move |_| {
-            timer.observe_duration();
-        })
-        .map_err(move |e| {
-            tracing::error!(
-                prev_epoch,
-                error = %e.as_report(),
-                "Failed to sync state store",
-            );
-            e.into()
-        })
+        .with_poll_timer("sync_epoch", prev_epoch, streaming_metrics)
         .boxed()
 }
 
+/// Computes the global completed-epoch frontier: the minimum, over all partial graphs, of the
+/// largest `prev_epoch` that has reached `Completed(Ok)` in that graph. `None` if there are no
+/// partial graphs, or if any of them has not yet completed a single epoch, since the frontier
+/// cannot advance past a graph that hasn't reported anything.
+fn compute_global_completed_epoch(
+    graph_states: &HashMap<PartialGraphId, PartialGraphManagedBarrierState>,
+) -> Option<u64> {
+    if graph_states.is_empty() {
+        return None;
+    }
+    graph_states
+        .values()
+        .try_fold(u64::MAX, |frontier, graph_state| {
+            graph_state.max_completed_epoch.map(|epoch| frontier.min(epoch))
+        })
+}
+
 pub(super) struct ManagedBarrierStateDebugInfo<'a> {
     graph_states: &'a HashMap<PartialGraphId, PartialGraphManagedBarrierState>,
 }
 
 impl Display for ManagedBarrierStateDebugInfo<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match compute_global_completed_epoch(self.graph_states) {
+            Some(epoch) => writeln!(f, "Global completed epoch: {}", epoch)?,
+            None => writeln!(f, "Global completed epoch: <none>")?,
+        }
         for (partial_graph_id, graph_states) in self.graph_states {
             writeln!(f, "--- Partial Group {}", partial_graph_id.0)?;
             write!(f, "{}", graph_states)?;
@@ -231,6 +481,12 @@ impl Display for &'_ PartialGraphManagedBarrierState {
                 ManagedBarrierStateInner::AllCollected => {
                     write!(f, "AllCollected")?;
                 }
+                ManagedBarrierStateInner::Retrying {
+                    attempt, next_at, ..
+                } => {
+                    let eta = next_at.saturating_duration_since(Instant::now());
+                    write!(f, "Retrying [attempt {}, next in {:?}]", attempt, eta)?;
+                }
                 ManagedBarrierStateInner::Completed(_) => {
                     write!(f, "Completed")?;
                 }
@@ -397,6 +653,10 @@ pub(super) struct PartialGraphManagedBarrierState {
 
     /// Manages the await-trees of all barriers.
     barrier_await_tree_reg: Option<await_tree::Registry>,
+
+    /// The largest `prev_epoch` that has reached `Completed(Ok)` in this partial graph so far.
+    /// Used to derive the cross-graph global completed-epoch frontier.
+    max_completed_epoch: Option<u64>,
 }
 
 impl PartialGraphManagedBarrierState {
@@ -422,6 +682,7 @@ impl PartialGraphManagedBarrierState {
             state_store,
             streaming_metrics,
             barrier_await_tree_reg,
+            max_completed_epoch: None,
         }
     }
 
@@ -437,6 +698,20 @@ impl PartialGraphManagedBarrierState {
     pub(super) fn is_empty(&self) -> bool {
         self.epoch_barrier_state_map.is_empty()
     }
+
+    /// Abandons all outstanding barrier state for this partial graph: every `Issued`,
+    /// `AllCollected` or `Retrying` entry in `epoch_barrier_state_map` is dropped without waiting
+    /// for its actors to `collect` or its `sync_epoch` to finish, the corresponding completion
+    /// futures in `await_epoch_completed_futures` are cancelled by being dropped, and the
+    /// `prev_barrier_table_ids` bookkeeping is released so a stale epoch is never reported as
+    /// completed for this graph.
+    pub(super) fn cancel(&mut self) {
+        self.epoch_barrier_state_map.clear();
+        self.await_epoch_completed_futures = Default::default();
+        self.prev_barrier_table_ids = None;
+        self.max_completed_epoch = None;
+        self.create_mview_progress.clear();
+    }
 }
 
 pub(crate) struct ManagedBarrierState {
@@ -447,6 +722,12 @@ pub(crate) struct ManagedBarrierState {
     actor_manager: Arc<StreamActorManager>,
 
     current_shared_context: Arc<SharedContext>,
+
+    /// The last value returned by [`Self::poll_global_committed_epoch`], so it only resolves
+    /// again once the frontier has strictly advanced. The frontier itself has a single source of
+    /// truth, [`compute_global_completed_epoch`], folding each graph's own
+    /// `max_completed_epoch`; this is just the dedup point on top of it.
+    last_global_committed_epoch: Option<u64>,
 }
 
 impl ManagedBarrierState {
@@ -468,6 +749,7 @@ impl ManagedBarrierState {
                 .collect(),
             actor_manager,
             current_shared_context,
+            last_global_committed_epoch: None,
         }
     }
 
@@ -477,6 +759,13 @@ impl ManagedBarrierState {
         }
     }
 
+    /// The minimum, over all partial graphs on this worker, of the largest `prev_epoch` that has
+    /// been fully collected and synced. This is a single monotonic "safe epoch" watermark for
+    /// the whole worker, so callers don't need to reason about each partial graph separately.
+    pub(crate) fn global_completed_epoch(&self) -> Option<u64> {
+        compute_global_completed_epoch(&self.graph_states)
+    }
+
     pub(crate) async fn abort_actors(&mut self) {
         for (actor_id, state) in &self.actor_states {
             tracing::debug!("force stopping actor {}", actor_id);
@@ -583,6 +872,49 @@ impl PartialGraphManagedBarrierState {
             }
         }
     }
+
+    /// Reconcile `mv_depended_subscriptions` against an authoritative snapshot of
+    /// `upstream_mv_table_id -> subscriber_ids` sent by the meta service, converging to it by
+    /// adding/removing the computed difference. Unlike `add_subscriptions`/`remove_subscriptions`,
+    /// which assume the incoming deltas are always consistent with local state and panic (in
+    /// debug builds) otherwise, this heals any divergence between the two views and only reports
+    /// it through a metric and a structured log, so meta and worker state can briefly disagree
+    /// (e.g. across recovery) without crashing.
+    pub(super) fn reconcile_subscriptions(
+        &mut self,
+        snapshot: HashMap<TableId, HashSet<u32>>,
+    ) {
+        let mut drifted = false;
+
+        self.mv_depended_subscriptions
+            .retain(|table_id, subscribers| match snapshot.get(table_id) {
+                Some(expected) => {
+                    if expected != subscribers {
+                        drifted = true;
+                        *subscribers = expected.clone();
+                    }
+                    true
+                }
+                None => {
+                    drifted = true;
+                    false
+                }
+            });
+        for (table_id, subscribers) in snapshot {
+            self.mv_depended_subscriptions.entry(table_id).or_insert_with(|| {
+                drifted = true;
+                subscribers
+            });
+        }
+
+        if drifted {
+            self.streaming_metrics.subscription_drift_count.inc();
+            warn!(
+                reconciled = ?self.mv_depended_subscriptions,
+                "healed subscription drift against meta's authoritative snapshot"
+            );
+        }
+    }
 }
 
 impl ManagedBarrierState {
@@ -687,19 +1019,95 @@ impl ManagedBarrierState {
         &mut self,
     ) -> impl Future<Output = (PartialGraphId, u64)> + '_ {
         poll_fn(|cx| {
+            let mut just_completed = None;
             for (partial_graph_id, graph_state) in &mut self.graph_states {
+                graph_state.warn_if_collection_stalled();
+                graph_state.refresh_retrying_states();
                 if let Poll::Ready(barrier) = graph_state.poll_next_completed_barrier(cx) {
                     if let Some(actors_to_stop) = barrier.all_stop_actors() {
                         self.current_shared_context.drop_actors(actors_to_stop);
                     }
-                    let partial_graph_id = *partial_graph_id;
-                    return Poll::Ready((partial_graph_id, barrier.epoch.prev));
+                    just_completed = Some((*partial_graph_id, barrier.epoch.prev));
+                    break;
                 }
             }
-            Poll::Pending
+            let Some((partial_graph_id, prev_epoch)) = just_completed else {
+                return Poll::Pending;
+            };
+            if let Some(global_completed_epoch) = compute_global_completed_epoch(&self.graph_states)
+            {
+                self.actor_manager
+                    .streaming_metrics
+                    .barrier_manager_global_completed_epoch
+                    .set(global_completed_epoch as i64);
+            }
+            Poll::Ready((partial_graph_id, prev_epoch))
         })
     }
 
+    /// Polls for the global committed-epoch frontier, returning `Ready` once it strictly
+    /// advances: the largest `prev_epoch` that *every* partial graph currently on this worker has
+    /// collected-and-synced past. Unlike [`Self::next_completed_epoch`], which demultiplexes
+    /// per-graph completions one at a time, this drains every graph's completion stream on each
+    /// poll before folding the frontier via [`compute_global_completed_epoch`], following the same
+    /// pattern as a partitioned client that tracks a per-partition frontier and only advances the
+    /// combined watermark to the minimum across all partitions.
+    ///
+    /// The frontier is recomputed over `self.graph_states` itself on every poll rather than a
+    /// separate copy of each graph's completed epoch, so a graph inserted into that map is picked
+    /// up automatically, and a graph removed from it (e.g. by [`Self::cancel_partial_graph`]) no
+    /// longer holds the frontier back.
+    pub(super) fn poll_global_committed_epoch(&mut self, cx: &mut Context<'_>) -> Poll<u64> {
+        for graph_state in self.graph_states.values_mut() {
+            graph_state.warn_if_collection_stalled();
+            graph_state.refresh_retrying_states();
+            while let Poll::Ready(barrier) = graph_state.poll_next_completed_barrier(cx) {
+                if let Some(actors_to_stop) = barrier.all_stop_actors() {
+                    self.current_shared_context.drop_actors(actors_to_stop);
+                }
+            }
+        }
+
+        match compute_global_completed_epoch(&self.graph_states) {
+            Some(epoch) if Some(epoch) > self.last_global_committed_epoch => {
+                self.last_global_committed_epoch = Some(epoch);
+                Poll::Ready(epoch)
+            }
+            _ => Poll::Pending,
+        }
+    }
+
+    /// Cancels all in-flight barrier state for a single partial graph, e.g. because its fragment
+    /// was dropped during reconfiguration, without waiting for its actors to `collect` or forcing
+    /// a full CN recovery. Other partial graphs are left untouched and keep collecting normally.
+    ///
+    /// Any actor whose outstanding `inflight_barriers` all belong to this partial graph has its
+    /// `monitor_task_handle` aborted too, since it has nothing left to watch.
+    pub(super) fn cancel_partial_graph(&mut self, partial_graph_id: PartialGraphId) {
+        let Some(mut graph_state) = self.graph_states.remove(&partial_graph_id) else {
+            return;
+        };
+        // `cancel` still runs on the removed state so its completion futures are dropped
+        // (cancelled) rather than leaked. The graph itself must not remain in `graph_states`:
+        // `compute_global_completed_epoch`/`poll_global_committed_epoch` fold over every entry in
+        // that map, and a cancelled graph's `max_completed_epoch` is permanently `None`, which
+        // would otherwise pin the global frontier at `None` forever.
+        graph_state.cancel();
+
+        for actor_state in self.actor_states.values_mut() {
+            let belongs_only_to_cancelled_graph = !actor_state.inflight_barriers.is_empty()
+                && actor_state
+                    .inflight_barriers
+                    .values()
+                    .all(|id| *id == partial_graph_id);
+            if belongs_only_to_cancelled_graph {
+                if let Some(monitor_task_handle) = actor_state.monitor_task_handle.take() {
+                    monitor_task_handle.abort();
+                }
+            }
+        }
+    }
+
     pub(super) fn collect(&mut self, actor_id: ActorId, epoch: EpochPair) {
         let (prev_partial_graph_id, is_finished) = self
             .actor_states
@@ -720,7 +1128,90 @@ impl ManagedBarrierState {
     }
 }
 
+/// How long the earliest in-flight barrier may go without collecting a new actor before we warn
+/// about which actors are holding it up.
+const BARRIER_COLLECT_WARN_THRESHOLD: Duration = Duration::from_secs(10);
+
 impl PartialGraphManagedBarrierState {
+    /// Checks whether the earliest in-flight barrier has been stuck waiting on actor collection
+    /// longer than [`BARRIER_COLLECT_WARN_THRESHOLD`], and if so emits a structured warning
+    /// naming the actors still holding it up so operators can tell which fragment is wedging
+    /// checkpoints, without changing the collection state machine itself.
+    fn warn_if_collection_stalled(&mut self) {
+        let Some((&prev_epoch, barrier_state)) = self.epoch_barrier_state_map.first_key_value()
+        else {
+            return;
+        };
+        let ManagedBarrierStateInner::Issued(IssuedState {
+            remaining_actors,
+            last_collect_progress_at,
+            ..
+        }) = &barrier_state.inner
+        else {
+            return;
+        };
+        let elapsed = last_collect_progress_at.elapsed();
+        if elapsed <= BARRIER_COLLECT_WARN_THRESHOLD {
+            return;
+        }
+
+        let laggard_actors = remaining_actors.clone();
+        self.streaming_metrics
+            .barrier_manager_laggard_actor_count
+            .set(laggard_actors.len() as i64);
+
+        // Only warn once per stall; `has_warned_stall` is cleared as soon as any actor collects.
+        let Some(BarrierState {
+            inner: ManagedBarrierStateInner::Issued(IssuedState { has_warned_stall, .. }),
+            ..
+        }) = self.epoch_barrier_state_map.get_mut(&prev_epoch)
+        else {
+            return;
+        };
+        if *has_warned_stall {
+            return;
+        }
+        *has_warned_stall = true;
+
+        tracing::warn!(
+            prev_epoch,
+            ?laggard_actors,
+            elapsed_secs = elapsed.as_secs_f64(),
+            "barrier collection stalled: the above actors have not reported collect for a long time",
+        );
+    }
+
+    /// Surfaces the latest attempt/backoff of any `Checkpoint` barrier whose `sync_epoch` is
+    /// being retried as a [`ManagedBarrierStateInner::Retrying`] snapshot, so operators inspecting
+    /// [`ManagedBarrierStateDebugInfo`] can see it's backing off instead of appearing stuck on
+    /// `AllCollected`. The retry itself keeps running inside the original future already queued in
+    /// `await_epoch_completed_futures`; this only updates the outward-facing state.
+    fn refresh_retrying_states(&mut self) {
+        for barrier_state in self.epoch_barrier_state_map.values_mut() {
+            let Some(retry_progress) = &barrier_state.retry_progress else {
+                continue;
+            };
+            let Some(RetryProgress {
+                kind,
+                table_ids,
+                attempt,
+                next_at,
+            }) = retry_progress.lock().unwrap().clone()
+            else {
+                continue;
+            };
+            if matches!(barrier_state.inner, ManagedBarrierStateInner::Completed(_)) {
+                continue;
+            }
+            barrier_state.inner = ManagedBarrierStateInner::Retrying {
+                kind,
+                table_ids,
+                attempt,
+                next_at,
+            };
+        }
+    }
+
     /// This method is called when barrier state is modified in either `Issued` or `Stashed`
     /// to transform the state to `AllCollected` and start state store `sync` when the barrier
     /// has been collected from all actors for an `Issued` barrier.
@@ -736,7 +1227,9 @@ impl PartialGraphManagedBarrierState {
                 ManagedBarrierStateInner::Issued(IssuedState {
                     remaining_actors, ..
                 }) if remaining_actors.is_empty() => {}
-                ManagedBarrierStateInner::AllCollected | ManagedBarrierStateInner::Completed(_) => {
+                ManagedBarrierStateInner::AllCollected
+                | ManagedBarrierStateInner::Retrying { .. }
+                | ManagedBarrierStateInner::Completed(_) => {
                     continue;
                 }
                 ManagedBarrierStateInner::Issued(_) => {
@@ -767,6 +1260,7 @@ impl PartialGraphManagedBarrierState {
                 .map(|(actor, state)| state.to_pb(actor))
                 .collect();
 
+            let retry_progress: RetryProgressHandle = Arc::new(Mutex::new(None));
             let complete_barrier_future = match kind {
                 BarrierKind::Unspecified => unreachable!(),
                 BarrierKind::Initial => {
@@ -780,11 +1274,16 @@ impl PartialGraphManagedBarrierState {
                 BarrierKind::Barrier => None,
                 BarrierKind::Checkpoint => Some(sync_epoch(
                     &self.state_store,
-                    &self.streaming_metrics,
+                    self.streaming_metrics.clone(),
                     prev_epoch,
+                    kind,
                     table_ids.expect("should be Some on BarrierKind::Checkpoint"),
+                    retry_progress.clone(),
                 )),
             };
+            if matches!(kind, BarrierKind::Checkpoint) {
+                barrier_state.retry_progress = Some(retry_progress);
+            }
 
             let barrier = barrier_state.barrier.clone();
 
@@ -794,6 +1293,7 @@ impl PartialGraphManagedBarrierState {
                     barrier,
                     self.barrier_await_tree_reg.as_ref(),
                     create_mview_progress,
+                    self.streaming_metrics.clone(),
                 )
             });
         }
@@ -822,6 +1322,8 @@ impl PartialGraphManagedBarrierState {
                 inner:
                     ManagedBarrierStateInner::Issued(IssuedState {
                         ref mut remaining_actors,
+                        ref mut last_collect_progress_at,
+                        ref mut has_warned_stall,
                         ..
                     }),
                 ..
@@ -832,6 +1334,8 @@ impl PartialGraphManagedBarrierState {
                     "the actor doesn't exist. actor_id: {:?}, curr_epoch: {:?}",
                     actor_id, epoch.curr
                 );
+                *last_collect_progress_at = Instant::now();
+                *has_warned_stall = false;
                 assert_eq!(barrier.epoch.curr, epoch.curr);
                 self.may_have_collected_all(epoch.prev);
             }
@@ -921,7 +1425,10 @@ impl PartialGraphManagedBarrierState {
                     barrier_inflight_latency: timer,
                     kind: barrier.kind,
                     table_ids,
+                    last_collect_progress_at: Instant::now(),
+                    has_warned_stall: false,
                 }),
+                retry_progress: None,
             },
         );
         self.may_have_collected_all(barrier.epoch.prev);
@@ -935,8 +1442,19 @@ impl PartialGraphManagedBarrierState {
                     .epoch_barrier_state_map
                     .get_mut(&barrier.epoch.prev)
                     .expect("should exist");
-                // sanity check on barrier state
-                assert_matches!(&state.inner, ManagedBarrierStateInner::AllCollected);
+                // sanity check on barrier state: `Retrying` is possible here since the retry
+                // loop lives inside the same future that just resolved.
+                assert_matches!(
+                    &state.inner,
+                    ManagedBarrierStateInner::AllCollected
+                        | ManagedBarrierStateInner::Retrying { .. }
+                );
+                if result.is_ok() {
+                    // `await_epoch_completed_futures` resolves in ascending epoch order, so this
+                    // is always monotonically increasing.
+                    self.max_completed_epoch = Some(barrier.epoch.prev);
+                }
+                state.retry_progress = None;
                 state.inner = ManagedBarrierStateInner::Completed(result);
                 barrier
             })
@@ -995,12 +1513,28 @@ impl PartialGraphManagedBarrierState {
 
 #[cfg(test)]
 mod tests {
+    use std::assert_matches::assert_matches;
     use std::collections::HashSet;
+    use std::future::poll_fn;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
 
+    use anyhow::anyhow;
+    use futures::FutureExt;
     use risingwave_common::util::epoch::test_epoch;
+    use risingwave_hummock_sdk::SyncResult;
+    use risingwave_pb::stream_plan::barrier::BarrierKind;
 
+    use super::{
+        instrument_complete_barrier_future, retry_sync_epoch, BarrierState,
+        ManagedBarrierStateInner, RetryProgress, RetryProgressHandle,
+        SYNC_EPOCH_RETRY_MAX_ATTEMPTS,
+    };
+    use crate::executor::monitor::StreamingMetrics;
     use crate::executor::Barrier;
     use crate::task::barrier_manager::managed_state::PartialGraphManagedBarrierState;
+    use crate::task::BarrierCompleteResult;
 
     #[tokio::test]
     async fn test_managed_state_add_actor() {
@@ -1104,4 +1638,229 @@ mod tests {
         );
         assert!(managed_barrier_state.epoch_barrier_state_map.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_refresh_retrying_states() {
+        let mut managed_barrier_state = PartialGraphManagedBarrierState::for_test();
+        let barrier = Barrier::new_test_barrier(test_epoch(1));
+        let retry_progress = Arc::new(Mutex::new(None));
+        managed_barrier_state.epoch_barrier_state_map.insert(
+            barrier.epoch.prev,
+            BarrierState {
+                barrier: barrier.clone(),
+                inner: ManagedBarrierStateInner::AllCollected,
+                retry_progress: Some(retry_progress.clone()),
+            },
+        );
+
+        // No failed attempt reported yet: the state stays `AllCollected`.
+        managed_barrier_state.refresh_retrying_states();
+        assert_matches!(
+            managed_barrier_state.epoch_barrier_state_map[&barrier.epoch.prev].inner,
+            ManagedBarrierStateInner::AllCollected
+        );
+
+        // A sync that fails twice: each failed attempt is surfaced as `Retrying`.
+        for attempt in 1..=2 {
+            *retry_progress.lock().unwrap() = Some(RetryProgress {
+                kind: BarrierKind::Checkpoint,
+                table_ids: HashSet::new(),
+                attempt,
+                next_at: Instant::now() + Duration::from_millis(100 * attempt as u64),
+            });
+            managed_barrier_state.refresh_retrying_states();
+            assert_matches!(
+                managed_barrier_state.epoch_barrier_state_map[&barrier.epoch.prev].inner,
+                ManagedBarrierStateInner::Retrying { attempt: a, .. } if a == attempt
+            );
+        }
+
+        // The future backing the retries eventually resolves; once `poll_next_completed_barrier`
+        // has set the terminal `Completed` state, further refreshes must not clobber it even if
+        // the now-stale retry handle is still observed.
+        managed_barrier_state
+            .epoch_barrier_state_map
+            .get_mut(&barrier.epoch.prev)
+            .unwrap()
+            .inner = ManagedBarrierStateInner::Completed(Ok(BarrierCompleteResult {
+            sync_result: Default::default(),
+            create_mview_progress: Default::default(),
+        }));
+        managed_barrier_state.refresh_retrying_states();
+        assert_matches!(
+            managed_barrier_state.epoch_barrier_state_map[&barrier.epoch.prev].inner,
+            ManagedBarrierStateInner::Completed(Ok(_))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancel_drains_graph_state() {
+        let mut cancelled = PartialGraphManagedBarrierState::for_test();
+        let mut untouched = PartialGraphManagedBarrierState::for_test();
+        for graph_state in [&mut cancelled, &mut untouched] {
+            let barrier1 = Barrier::new_test_barrier(test_epoch(1));
+            let barrier2 = Barrier::new_test_barrier(test_epoch(2));
+            graph_state.transform_to_issued(&barrier1, HashSet::from([1, 2]), HashSet::new());
+            graph_state.transform_to_issued(&barrier2, HashSet::from([1, 2]), HashSet::new());
+        }
+
+        cancelled.cancel();
+        assert!(cancelled.epoch_barrier_state_map.is_empty());
+        assert!(cancelled.create_mview_progress.is_empty());
+        assert!(cancelled.prev_barrier_table_ids.is_none());
+
+        // The untouched graph keeps collecting normally, unaffected by the other graph's
+        // cancellation.
+        let barrier1 = Barrier::new_test_barrier(test_epoch(1));
+        untouched.collect(1, barrier1.epoch);
+        untouched.collect(2, barrier1.epoch);
+        assert_eq!(untouched.pop_next_completed_epoch().await, test_epoch(0));
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_graph_excluded_from_global_frontier() {
+        use std::collections::HashMap;
+
+        use super::{PartialGraphId, compute_global_completed_epoch};
+
+        let mut untouched = PartialGraphManagedBarrierState::for_test();
+        untouched.max_completed_epoch = Some(test_epoch(5));
+
+        let mut graph_states = HashMap::new();
+        graph_states.insert(PartialGraphId::new(0), untouched);
+        assert_eq!(
+            compute_global_completed_epoch(&graph_states),
+            Some(test_epoch(5))
+        );
+
+        // `cancel_partial_graph` must remove the cancelled graph from `graph_states` entirely,
+        // not just drain it in place: a drained-but-still-present entry has
+        // `max_completed_epoch = None` forever, which would otherwise permanently pin the global
+        // frontier to `None` even though the other (untouched) graph keeps completing epochs.
+        let mut cancelled = PartialGraphManagedBarrierState::for_test();
+        cancelled.max_completed_epoch = None;
+        graph_states.insert(PartialGraphId::new(1), cancelled);
+        assert_eq!(compute_global_completed_epoch(&graph_states), None);
+
+        graph_states.remove(&PartialGraphId::new(1));
+        assert_eq!(
+            compute_global_completed_epoch(&graph_states),
+            Some(test_epoch(5))
+        );
+    }
+
+    /// Wires a `retry_sync_epoch` future, driven by a fake fallible `attempt_sync`, through the
+    /// same `await_epoch_completed_futures`/`poll_next_completed_barrier` machinery a real
+    /// `Checkpoint` barrier uses, so the terminal `epoch_barrier_state_map` state can be asserted
+    /// without depending on a real, failure-injectable `HummockStorage`.
+    fn push_retry_sync_future(
+        managed_barrier_state: &mut PartialGraphManagedBarrierState,
+        barrier: &Barrier,
+        retry_progress: RetryProgressHandle,
+        attempts: Arc<AtomicU32>,
+        fail_until_attempt: u32,
+    ) {
+        let metrics = Arc::new(StreamingMetrics::unused());
+        let sync_future = retry_sync_epoch(
+            move || {
+                let attempts = attempts.clone();
+                async move {
+                    let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+                    if attempt < fail_until_attempt {
+                        Err(anyhow!("transient sync failure").into())
+                    } else {
+                        Ok(SyncResult::default())
+                    }
+                }
+            },
+            metrics.clone(),
+            barrier.epoch.prev,
+            BarrierKind::Checkpoint,
+            HashSet::new(),
+            retry_progress,
+        )
+        .boxed();
+
+        managed_barrier_state
+            .await_epoch_completed_futures
+            .push_back(instrument_complete_barrier_future(
+                Some(sync_future),
+                barrier.clone(),
+                None,
+                vec![],
+                metrics,
+            ));
+    }
+
+    #[tokio::test]
+    async fn test_sync_epoch_retries_then_succeeds() {
+        let mut managed_barrier_state = PartialGraphManagedBarrierState::for_test();
+        let barrier = Barrier::new_test_barrier(test_epoch(1));
+        let retry_progress = Arc::new(Mutex::new(None));
+        managed_barrier_state.epoch_barrier_state_map.insert(
+            barrier.epoch.prev,
+            BarrierState {
+                barrier: barrier.clone(),
+                inner: ManagedBarrierStateInner::AllCollected,
+                retry_progress: Some(retry_progress.clone()),
+            },
+        );
+
+        let attempts = Arc::new(AtomicU32::new(0));
+        push_retry_sync_future(
+            &mut managed_barrier_state,
+            &barrier,
+            retry_progress,
+            attempts.clone(),
+            2,
+        );
+
+        let completed =
+            poll_fn(|cx| managed_barrier_state.poll_next_completed_barrier(cx)).await;
+        assert_eq!(completed.epoch.prev, barrier.epoch.prev);
+        // Fails on attempt 0 and 1, succeeds on attempt 2: 3 calls total.
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert_matches!(
+            managed_barrier_state.epoch_barrier_state_map[&barrier.epoch.prev].inner,
+            ManagedBarrierStateInner::Completed(Ok(_))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_epoch_exhausts_retries() {
+        let mut managed_barrier_state = PartialGraphManagedBarrierState::for_test();
+        let barrier = Barrier::new_test_barrier(test_epoch(1));
+        let retry_progress = Arc::new(Mutex::new(None));
+        managed_barrier_state.epoch_barrier_state_map.insert(
+            barrier.epoch.prev,
+            BarrierState {
+                barrier: barrier.clone(),
+                inner: ManagedBarrierStateInner::AllCollected,
+                retry_progress: Some(retry_progress.clone()),
+            },
+        );
+
+        // Never succeeds: exhausts all `SYNC_EPOCH_RETRY_MAX_ATTEMPTS` retries and surfaces as a
+        // terminal `Completed(Err(_))`, rather than retrying forever.
+        let attempts = Arc::new(AtomicU32::new(0));
+        push_retry_sync_future(
+            &mut managed_barrier_state,
+            &barrier,
+            retry_progress,
+            attempts.clone(),
+            u32::MAX,
+        );
+
+        let completed =
+            poll_fn(|cx| managed_barrier_state.poll_next_completed_barrier(cx)).await;
+        assert_eq!(completed.epoch.prev, barrier.epoch.prev);
+        assert_eq!(
+            attempts.load(Ordering::SeqCst) as u64,
+            SYNC_EPOCH_RETRY_MAX_ATTEMPTS as u64 + 1
+        );
+        assert_matches!(
+            managed_barrier_state.epoch_barrier_state_map[&barrier.epoch.prev].inner,
+            ManagedBarrierStateInner::Completed(Err(_))
+        );
+    }
 }